@@ -0,0 +1,50 @@
+//! OS keychain-backed storage for the Slack/Asana OAuth tokens the fetch
+//! commands need, so credentials never touch disk in plaintext.
+//!
+//! Requires the `keyring` crate as a `src-tauri/Cargo.toml` dependency
+//! (this checkout doesn't have a manifest yet to add it to).
+
+use keyring::Entry;
+
+/// Keychain "service" namespace every credential entry is stored under.
+const KEYCHAIN_SERVICE: &str = "meta-layer";
+
+fn entry_for(service: &str) -> Result<Entry, String> {
+    Entry::new(KEYCHAIN_SERVICE, service).map_err(|err| err.to_string())
+}
+
+/// Persists `token` for `service` (e.g. `"slack"` or `"asana"`) in the OS
+/// keychain (Keychain on macOS, Credential Manager on Windows, libsecret on
+/// Linux).
+#[tauri::command]
+pub fn save_token(service: String, token: String) -> Result<(), String> {
+    entry_for(&service)?
+        .set_password(&token)
+        .map_err(|err| err.to_string())
+}
+
+/// Reads back the token stored for `service`, if any.
+#[tauri::command]
+pub fn get_token(service: String) -> Result<Option<String>, String> {
+    match entry_for(&service)?.get_password() {
+        Ok(token) => Ok(Some(token)),
+        Err(keyring::Error::NoEntry) => Ok(None),
+        Err(err) => Err(err.to_string()),
+    }
+}
+
+/// Removes the token stored for `service`, if any.
+#[tauri::command]
+pub fn clear_token(service: String) -> Result<(), String> {
+    match entry_for(&service)?.delete_credential() {
+        Ok(()) | Err(keyring::Error::NoEntry) => Ok(()),
+        Err(err) => Err(err.to_string()),
+    }
+}
+
+/// Reports whether a token is currently stored for `service`, so the
+/// frontend can gate its UI on auth state without handling the token itself.
+#[tauri::command]
+pub fn is_authenticated(service: String) -> Result<bool, String> {
+    Ok(get_token(service)?.is_some())
+}
@@ -0,0 +1,192 @@
+//! Typed fetch commands for the Slack/Asana sources the meta layer aggregates.
+//!
+//! The frontend should call these instead of hitting `fetch()` directly so that
+//! every source is normalized into a single `MetaItem` shape before it reaches
+//! the inbox UI.
+
+use serde::{Deserialize, Serialize};
+use tauri_plugin_http::reqwest;
+
+use super::auth::get_token;
+
+/// A single message returned by the Slack conversations history API.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SlackMessage {
+    pub ts: String,
+    pub user: Option<String>,
+    pub text: String,
+    #[serde(default)]
+    pub permalink: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SlackHistoryResponse {
+    ok: bool,
+    #[serde(default)]
+    error: Option<String>,
+    #[serde(default)]
+    messages: Vec<SlackMessage>,
+    #[serde(default)]
+    response_metadata: Option<SlackResponseMetadata>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SlackResponseMetadata {
+    #[serde(default)]
+    next_cursor: String,
+}
+
+/// A single task returned by the Asana tasks API.
+#[derive(Debug, Clone, Deserialize)]
+pub struct AsanaTask {
+    pub gid: String,
+    pub name: String,
+    #[serde(default)]
+    pub notes: String,
+    #[serde(default)]
+    pub permalink_url: Option<String>,
+    #[serde(default)]
+    pub modified_at: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct AsanaTasksResponse {
+    #[serde(default)]
+    data: Vec<AsanaTask>,
+    #[serde(default)]
+    errors: Vec<AsanaError>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AsanaError {
+    message: String,
+}
+
+/// One page of normalized Slack messages plus the cursor to fetch the next
+/// page with, if Slack reported one.
+#[derive(Debug, Serialize)]
+pub struct SlackMessagePage {
+    pub items: Vec<MetaItem>,
+    pub next_cursor: Option<String>,
+}
+
+/// A source item normalized for the aggregated meta layer inbox.
+#[derive(Debug, Clone, Serialize)]
+pub struct MetaItem {
+    pub id: String,
+    pub source: String,
+    pub title: String,
+    pub body: String,
+    pub url: Option<String>,
+    pub timestamp: String,
+}
+
+impl From<SlackMessage> for MetaItem {
+    fn from(msg: SlackMessage) -> Self {
+        MetaItem {
+            id: msg.ts.clone(),
+            source: "slack".to_string(),
+            title: msg.user.unwrap_or_else(|| "slack".to_string()),
+            body: msg.text,
+            url: msg.permalink,
+            timestamp: msg.ts,
+        }
+    }
+}
+
+impl From<AsanaTask> for MetaItem {
+    fn from(task: AsanaTask) -> Self {
+        MetaItem {
+            id: task.gid,
+            source: "asana".to_string(),
+            title: task.name,
+            body: task.notes,
+            url: task.permalink_url,
+            timestamp: task.modified_at,
+        }
+    }
+}
+
+/// Fetches a page of Slack messages for `channel`, starting at `cursor`, and
+/// normalizes them into `MetaItem`s. The returned `next_cursor` should be
+/// passed back in as `cursor` to fetch the following page.
+#[tauri::command]
+pub async fn fetch_slack_messages(
+    channel: String,
+    cursor: Option<String>,
+) -> Result<SlackMessagePage, String> {
+    let client = reqwest::Client::new();
+    let mut request = client
+        .get("https://slack.com/api/conversations.history")
+        .query(&[("channel", channel.as_str())]);
+
+    if let Some(cursor) = cursor.as_deref() {
+        request = request.query(&[("cursor", cursor)]);
+    }
+
+    if let Some(token) = get_token("slack".to_string())? {
+        request = request.bearer_auth(token);
+    }
+
+    let response = request
+        .send()
+        .await
+        .map_err(|err| format!("failed to reach Slack: {err}"))?
+        .json::<SlackHistoryResponse>()
+        .await
+        .map_err(|err| format!("failed to parse Slack response: {err}"))?;
+
+    if !response.ok {
+        return Err(format!(
+            "Slack API error: {}",
+            response.error.unwrap_or_else(|| "unknown error".to_string())
+        ));
+    }
+
+    let next_cursor = response
+        .response_metadata
+        .map(|meta| meta.next_cursor)
+        .filter(|cursor| !cursor.is_empty());
+
+    Ok(SlackMessagePage {
+        items: response.messages.into_iter().map(MetaItem::from).collect(),
+        next_cursor,
+    })
+}
+
+/// Fetches the open tasks for `project` within `workspace` and normalizes them
+/// into `MetaItem`s.
+#[tauri::command]
+pub async fn fetch_asana_tasks(
+    workspace: String,
+    project: String,
+) -> Result<Vec<MetaItem>, String> {
+    let client = reqwest::Client::new();
+    let mut request = client
+        .get("https://app.asana.com/api/1.0/tasks")
+        .query(&[
+            ("workspace", workspace.as_str()),
+            ("project", project.as_str()),
+            ("completed_since", "now"),
+            ("opt_fields", "name,notes,permalink_url,modified_at"),
+        ]);
+
+    if let Some(token) = get_token("asana".to_string())? {
+        request = request.bearer_auth(token);
+    }
+
+    let response = request
+        .send()
+        .await
+        .map_err(|err| format!("failed to reach Asana: {err}"))?
+        .json::<AsanaTasksResponse>()
+        .await
+        .map_err(|err| format!("failed to parse Asana response: {err}"))?;
+
+    if !response.errors.is_empty() {
+        let messages: Vec<_> = response.errors.into_iter().map(|err| err.message).collect();
+        return Err(format!("Asana API error: {}", messages.join("; ")));
+    }
+
+    Ok(response.data.into_iter().map(MetaItem::from).collect())
+}
@@ -0,0 +1,5 @@
+pub mod auth;
+pub mod integrations;
+pub mod notifications;
+pub mod polling;
+pub mod windows;
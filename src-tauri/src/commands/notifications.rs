@@ -0,0 +1,89 @@
+//! Desktop notifications for newly fetched Slack/Asana items.
+//!
+//! Polling the Slack/Asana commands on an interval will naturally re-fetch
+//! items that were already seen, so we keep a set of item IDs we've already
+//! notified the user about and only surface genuinely new ones. The set is
+//! bounded (see `MAX_NOTIFIED_ITEMS`) and in-memory only: it resets on
+//! restart, so a fresh launch will re-notify whatever the first poll sees.
+//!
+//! Requires the `tauri-plugin-notification` crate as a `src-tauri/Cargo.toml`
+//! dependency, plus a `notification:default` entry in the app's capabilities
+//! file, for `.plugin(tauri_plugin_notification::init())` in `lib.rs` to be
+//! permitted at runtime (this checkout doesn't have a manifest/capabilities
+//! file yet to add those to).
+
+use std::collections::{HashSet, VecDeque};
+use std::sync::Mutex;
+
+use tauri::{AppHandle, State};
+use tauri_plugin_notification::NotificationExt;
+
+use super::integrations::MetaItem;
+
+/// Caps how many item IDs we remember, so a long-running process doesn't
+/// grow this set forever; oldest IDs are evicted first.
+const MAX_NOTIFIED_ITEMS: usize = 2_000;
+
+/// Tracks which `MetaItem` IDs have already triggered a notification, so
+/// repeated polls don't re-notify the same Slack message or Asana task.
+#[derive(Default)]
+pub struct NotifiedItems(pub Mutex<NotifiedItemsInner>);
+
+#[derive(Default)]
+pub struct NotifiedItemsInner {
+    seen: HashSet<String>,
+    insertion_order: VecDeque<String>,
+}
+
+impl NotifiedItemsInner {
+    /// Records `id` as seen, returning `true` if it wasn't already. Evicts
+    /// the oldest tracked ID once the set grows past `MAX_NOTIFIED_ITEMS`.
+    fn insert(&mut self, id: String) -> bool {
+        if !self.seen.insert(id.clone()) {
+            return false;
+        }
+
+        self.insertion_order.push_back(id);
+        if self.insertion_order.len() > MAX_NOTIFIED_ITEMS {
+            if let Some(oldest) = self.insertion_order.pop_front() {
+                self.seen.remove(&oldest);
+            }
+        }
+
+        true
+    }
+}
+
+/// Notifies the user about any `items` that haven't already been notified,
+/// then records them as seen.
+pub fn notify_items(
+    app: &AppHandle,
+    notified: &Mutex<NotifiedItemsInner>,
+    items: Vec<MetaItem>,
+) -> Result<(), String> {
+    let mut notified = notified.lock().map_err(|err| err.to_string())?;
+
+    for item in items {
+        if !notified.insert(item.id.clone()) {
+            continue;
+        }
+
+        app.notification()
+            .builder()
+            .title(format!("{}: {}", item.source, item.title))
+            .body(item.body)
+            .show()
+            .map_err(|err| err.to_string())?;
+    }
+
+    Ok(())
+}
+
+#[tauri::command]
+pub fn notify_new_items(
+    app: AppHandle,
+    notified: State<'_, NotifiedItems>,
+    items: Vec<MetaItem>,
+) -> Result<(), String> {
+    notify_items(&app, &notified.0, items)
+}
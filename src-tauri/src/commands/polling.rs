@@ -0,0 +1,114 @@
+//! Background polling of the Slack/Asana sources, decoupled from the UI.
+//!
+//! Rather than have the frontend `invoke()` the fetch commands on a
+//! `setInterval`, we run the poll loop in Rust and push results to the
+//! frontend via the `meta://items-updated` event, and route them through
+//! the desktop notifier. This keeps polling alive even while the window
+//! isn't focused and lets us debounce slow ticks.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use serde::Deserialize;
+use tauri::async_runtime::JoinHandle;
+use tauri::{AppHandle, Emitter, Manager, State};
+
+use super::integrations::{fetch_asana_tasks, fetch_slack_messages, MetaItem};
+use super::notifications::{notify_items, NotifiedItems};
+
+/// Event emitted to the frontend whenever a poll tick fetches fresh items.
+pub const ITEMS_UPDATED_EVENT: &str = "meta://items-updated";
+
+/// Which Slack channel / Asana workspace+project to poll. `None` for a
+/// field means that source is skipped — there's no sensible default
+/// channel or project ID to poll against, so the frontend must supply one
+/// via `start_polling` before that source's items show up.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct PollSources {
+    pub slack_channel: Option<String>,
+    pub asana_workspace: Option<String>,
+    pub asana_project: Option<String>,
+}
+
+/// Holds the handle of the currently running poll loop, if any, plus a flag
+/// used to debounce overlapping ticks.
+#[derive(Default)]
+pub struct PollingHandle {
+    task: Mutex<Option<JoinHandle<()>>>,
+    in_flight: Arc<AtomicBool>,
+}
+
+/// Starts polling the Slack/Asana fetch commands every `interval_secs`
+/// seconds against `sources`, replacing any poll loop that was already
+/// running. Results are pushed through the desktop notifier and emitted to
+/// the frontend as `meta://items-updated`.
+#[tauri::command]
+pub fn start_polling(
+    app: AppHandle,
+    state: State<'_, PollingHandle>,
+    interval_secs: u64,
+    sources: PollSources,
+) {
+    stop_polling(state.clone());
+
+    let in_flight = state.in_flight.clone();
+    let handle = tauri::async_runtime::spawn(async move {
+        let mut ticker = tokio::time::interval(Duration::from_secs(interval_secs.max(1)));
+        loop {
+            ticker.tick().await;
+
+            // Skip spawning a new tick's work while the previous one is
+            // still running, so a slow request can't stack up.
+            if in_flight.swap(true, Ordering::SeqCst) {
+                continue;
+            }
+
+            let app = app.clone();
+            let in_flight = in_flight.clone();
+            let sources = sources.clone();
+            tauri::async_runtime::spawn(async move {
+                let result = poll_once(&sources).await;
+                in_flight.store(false, Ordering::SeqCst);
+
+                match result {
+                    Ok(items) => {
+                        if let Some(notified) = app.try_state::<NotifiedItems>() {
+                            if let Err(err) = notify_items(&app, &notified.0, items.clone()) {
+                                eprintln!("meta layer notify failed: {err}");
+                            }
+                        }
+                        let _ = app.emit(ITEMS_UPDATED_EVENT, items);
+                    }
+                    Err(err) => {
+                        eprintln!("meta layer poll failed: {err}");
+                    }
+                }
+            });
+        }
+    });
+
+    *state.task.lock().expect("polling task mutex poisoned") = Some(handle);
+}
+
+/// Stops the currently running poll loop, if any.
+#[tauri::command]
+pub fn stop_polling(state: State<'_, PollingHandle>) {
+    if let Some(handle) = state.task.lock().expect("polling task mutex poisoned").take() {
+        handle.abort();
+    }
+}
+
+async fn poll_once(sources: &PollSources) -> Result<Vec<MetaItem>, String> {
+    let mut items = Vec::new();
+
+    if let Some(channel) = &sources.slack_channel {
+        items.extend(fetch_slack_messages(channel.clone(), None).await?.items);
+    }
+
+    if let (Some(workspace), Some(project)) = (&sources.asana_workspace, &sources.asana_project) {
+        items.extend(fetch_asana_tasks(workspace.clone(), project.clone()).await?);
+    }
+
+    Ok(items)
+}
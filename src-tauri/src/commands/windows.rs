@@ -0,0 +1,89 @@
+//! Per-source detail windows, e.g. popping a Slack thread or Asana task out
+//! of the aggregated inbox into its own panel.
+
+use std::collections::HashSet;
+use std::sync::Mutex;
+
+use tauri::{AppHandle, Emitter, Manager, State, WebviewUrl, WebviewWindowBuilder, WindowEvent};
+
+/// Event emitted when a detached source window is closed, so the main inbox
+/// can clear its "open in panel" indicator for that item.
+pub const WINDOW_CLOSED_EVENT: &str = "window-closed";
+
+/// Tracks the labels of currently open per-source detail windows: a label is
+/// added when its window is built and removed when that window is destroyed.
+/// Whether an item's window is already open (so it is focused instead of
+/// duplicated) is looked up with `get_webview_window`, not from this set.
+#[derive(Default)]
+pub struct OpenSourceWindows(pub Mutex<HashSet<String>>);
+
+/// Tauri window labels only permit `[A-Za-z0-9_/:-]`, but source item IDs
+/// don't respect that — a Slack message `ts` like `"1699999999.000100"`
+/// contains a `.`. Replace anything else with `_` so building the label
+/// never fails.
+fn sanitize_label_part(part: &str) -> String {
+    part.chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || matches!(c, '-' | '/' | ':' | '_') {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+fn window_label(source: &str, item_id: &str) -> String {
+    format!(
+        "detail-{}-{}",
+        sanitize_label_part(source),
+        sanitize_label_part(item_id)
+    )
+}
+
+/// Opens a detached window showing `item_id` from `source`, focusing the
+/// existing window instead of creating a duplicate if it's already open.
+#[tauri::command]
+pub fn open_source_window(
+    app: AppHandle,
+    open_windows: State<'_, OpenSourceWindows>,
+    source: String,
+    item_id: String,
+) -> Result<(), String> {
+    let label = window_label(&source, &item_id);
+
+    if let Some(existing) = app.get_webview_window(&label) {
+        existing.set_focus().map_err(|err| err.to_string())?;
+        return Ok(());
+    }
+
+    let window = WebviewWindowBuilder::new(
+        &app,
+        &label,
+        WebviewUrl::App(format!("/detail/{source}/{item_id}").into()),
+    )
+    .title(format!("{source}: {item_id}"))
+    .build()
+    .map_err(|err| err.to_string())?;
+
+    open_windows
+        .0
+        .lock()
+        .map_err(|err| err.to_string())?
+        .insert(label.clone());
+
+    let app_for_close = app.clone();
+    let label_for_close = label.clone();
+    window.on_window_event(move |event| {
+        if let WindowEvent::Destroyed = event {
+            if let Some(state) = app_for_close.try_state::<OpenSourceWindows>() {
+                if let Ok(mut open) = state.0.lock() {
+                    open.remove(&label_for_close);
+                }
+            }
+            let _ = app_for_close.emit(WINDOW_CLOSED_EVENT, label_for_close.clone());
+        }
+    });
+
+    Ok(())
+}
@@ -1,3 +1,16 @@
+mod commands;
+
+use tauri::Manager;
+
+use commands::auth::{clear_token, get_token, is_authenticated, save_token};
+use commands::integrations::{fetch_asana_tasks, fetch_slack_messages};
+use commands::notifications::{notify_new_items, NotifiedItems};
+use commands::polling::{start_polling, stop_polling, PollSources, PollingHandle};
+use commands::windows::{open_source_window, OpenSourceWindows};
+
+/// Default interval, in seconds, between background polls once the app starts.
+const DEFAULT_POLL_INTERVAL_SECS: u64 = 60;
+
 // Learn more about Tauri commands at https://tauri.app/develop/calling-rust/
 #[tauri::command]
 fn greet(name: &str) -> String {
@@ -11,8 +24,38 @@ pub fn run() {
         .plugin(tauri_plugin_shell::init())   // <--- Required for opening links in browser
         .plugin(tauri_plugin_http::init())    // <--- Required for fetching Slack/Asana data
         .plugin(tauri_plugin_opener::init())  // <--- Standard Tauri file opener
+        .plugin(tauri_plugin_notification::init()) // <--- Required for new-item desktop notifications
         // ---------------------------------------
-        .invoke_handler(tauri::generate_handler![greet])
+        .manage(NotifiedItems::default())
+        .manage(PollingHandle::default())
+        .manage(OpenSourceWindows::default())
+        .setup(|app| {
+            // No Slack channel / Asana project is configured yet at startup,
+            // so each tick of this loop fetches nothing and emits an empty
+            // `meta://items-updated` until the frontend calls `start_polling`
+            // again with real `PollSources` once the user has authenticated
+            // and picked what to follow.
+            start_polling(
+                app.handle().clone(),
+                app.state(),
+                DEFAULT_POLL_INTERVAL_SECS,
+                PollSources::default(),
+            );
+            Ok(())
+        })
+        .invoke_handler(tauri::generate_handler![
+            greet,
+            fetch_slack_messages,
+            fetch_asana_tasks,
+            notify_new_items,
+            start_polling,
+            stop_polling,
+            save_token,
+            get_token,
+            clear_token,
+            is_authenticated,
+            open_source_window
+        ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
 }